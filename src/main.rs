@@ -1,3 +1,5 @@
+mod monitor;
+
 use arboard::Clipboard;
 use clap::Parser;
 use crossterm::event;
@@ -12,23 +14,38 @@ use crossterm::ExecutableCommand;
 use error_stack::Context;
 use error_stack::Result;
 use error_stack::ResultExt;
+#[cfg(not(target_os = "linux"))]
+use monitor::Clip;
+#[cfg(not(target_os = "linux"))]
+use monitor::ClipboardMonitor;
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 use sqlite::Connection;
+use sqlite::State;
 use std::fmt;
 use std::fs;
 use std::io;
 use std::io::stdout;
 use std::io::IsTerminal;
 use std::io::Read;
+use std::path::Path;
+use std::path::PathBuf;
+#[cfg(target_os = "linux")]
 use std::thread;
+#[cfg(target_os = "linux")]
 use std::time;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 struct Args {
     #[arg(short, long)]
     daemon: bool,
+    #[arg(long)]
+    export: Option<PathBuf>,
+    #[arg(long)]
+    import: Option<PathBuf>,
 }
 
 fn main() -> Result<(), Error> {
@@ -44,6 +61,54 @@ fn main() -> Result<(), Error> {
     let db = sqlite::open(db_path)
         .change_context(Error)
         .attach_printable("unable to open the sqlite database")?;
+    db.execute(
+        "CREATE TABLE IF NOT EXISTS clips (
+             clip TEXT PRIMARY KEY,
+             selection TEXT NOT NULL DEFAULT 'clipboard',
+             created_at INTEGER NOT NULL DEFAULT 0
+         );
+         CREATE TABLE IF NOT EXISTS images (
+             id INTEGER PRIMARY KEY AUTOINCREMENT,
+             width INTEGER NOT NULL,
+             height INTEGER NOT NULL,
+             data BLOB NOT NULL,
+             selection TEXT NOT NULL DEFAULT 'clipboard',
+             created_at INTEGER NOT NULL DEFAULT 0
+         );
+         CREATE VIRTUAL TABLE IF NOT EXISTS clips_fts USING fts5(
+             clip, content='clips', content_rowid='rowid'
+         );
+         CREATE TRIGGER IF NOT EXISTS clips_fts_ai AFTER INSERT ON clips BEGIN
+             INSERT INTO clips_fts(rowid, clip) VALUES (new.rowid, new.clip);
+         END;
+         CREATE TRIGGER IF NOT EXISTS clips_fts_ad AFTER DELETE ON clips BEGIN
+             INSERT INTO clips_fts(clips_fts, rowid, clip) VALUES('delete', old.rowid, old.clip);
+         END;",
+    )
+    .change_context(Error)
+    .attach_printable("unable to create the clips table")?;
+    // The CREATE TABLEs above only give fresh databases `created_at`; a
+    // database from before this column existed needs it added on top, and
+    // SQLite has no `ADD COLUMN IF NOT EXISTS`, so check first.
+    add_created_at_column(&db, "clips")
+        .change_context(Error)
+        .attach_printable("unable to add clips.created_at")?;
+    add_created_at_column(&db, "images")
+        .change_context(Error)
+        .attach_printable("unable to add images.created_at")?;
+    // clips_fts only gets filled in by the triggers above, so a database
+    // that already had clips before this index existed needs an explicit
+    // rebuild or none of that history is searchable.
+    db.execute("INSERT INTO clips_fts(clips_fts) VALUES('rebuild');")
+        .change_context(Error)
+        .attach_printable("unable to backfill the clips search index")?;
+
+    if let Some(path) = args.export.as_deref() {
+        return export_db(&db, path);
+    }
+    if let Some(path) = args.import.as_deref() {
+        return import_db(&db, path);
+    }
 
     if args.daemon {
         return daemon(db);
@@ -58,15 +123,9 @@ fn main() -> Result<(), Error> {
                 .change_context(Error)?;
             let mut terminal =
                 Terminal::new(CrosstermBackend::new(stdout())).change_context(Error)?;
-            let mut rows = Vec::new();
-
-            let query = "SELECT clip FROM clips;";
-            db.iterate(query, |pairs| {
-                rows.push([pairs[0].1.unwrap().into()]);
-                true
-            })
-            .change_context(Error)
-            .attach_printable("insertion into database failed")?;
+            let rows = read_rows(&db)
+                .change_context(Error)
+                .attach_printable("unable to read clip history from database")?;
 
             let mut state = AppState::new(rows, db);
 
@@ -93,12 +152,26 @@ fn main() -> Result<(), Error> {
             .change_context(Error)
             .attach_printable("unable to read in data from stdin")?;
 
-        let query = format!(
-            "CREATE TABLE IF NOT EXISTS clips (clip TEXT PRIMARY KEY);
-             INSERT OR IGNORE INTO clips (clip) VALUES ('{clip}');"
-        );
-
-        db.execute(query)
+        let mut statement = db
+            .prepare(
+                "INSERT OR IGNORE INTO clips (clip, selection, created_at) VALUES (?1, ?2, ?3);",
+            )
+            .change_context(Error)
+            .attach_printable("unable to prepare insert statement")?;
+        statement
+            .bind((1, clip.as_str()))
+            .change_context(Error)
+            .attach_printable("unable to bind clip to insert statement")?;
+        statement
+            .bind((2, "clipboard"))
+            .change_context(Error)
+            .attach_printable("unable to bind selection to insert statement")?;
+        statement
+            .bind((3, now_millis()))
+            .change_context(Error)
+            .attach_printable("unable to bind created_at to insert statement")?;
+        statement
+            .next()
             .change_context(Error)
             .attach_printable("insertion into database failed")?;
         let mut clipboard = Clipboard::new()
@@ -112,11 +185,62 @@ fn main() -> Result<(), Error> {
     }
 }
 
+/// Add a `created_at` column to `table` if it doesn't already have one.
+/// Existing rows default to 0 (the unix epoch), which is correct since
+/// they're older than anything that will ever be timestamped for real.
+///
+/// `table` is always one of our own hardcoded table names, never user
+/// input, so interpolating it into the SQL here is safe; SQLite has no way
+/// to bind a table name as a parameter.
+fn add_created_at_column(db: &Connection, table: &str) -> std::result::Result<(), sqlite::Error> {
+    let mut columns = db.prepare(format!("PRAGMA table_info({table});"))?;
+    let mut has_created_at = false;
+    while let State::Row = columns.next()? {
+        if columns.read::<String, _>("name")? == "created_at" {
+            has_created_at = true;
+            break;
+        }
+    }
+
+    if !has_created_at {
+        db.execute(format!(
+            "ALTER TABLE {table} ADD COLUMN created_at INTEGER NOT NULL DEFAULT 0;"
+        ))?;
+    }
+    Ok(())
+}
+
 fn handle_events(app_state: &mut AppState) -> Result<bool, Error> {
     if event::poll(std::time::Duration::from_millis(50)).change_context(Error)? {
         if let Event::Key(key) = event::read().change_context(Error)? {
             match (key.kind, key.code) {
-                (KeyEventKind::Press, KeyCode::Esc) => return Ok(true),
+                (KeyEventKind::Press, KeyCode::Esc) => {
+                    if matches!(app_state.mode, Mode::Search) {
+                        app_state.mode = Mode::Normal;
+                        app_state.query.clear();
+                        refilter(app_state)?;
+                    } else {
+                        return Ok(true);
+                    }
+                }
+                (KeyEventKind::Press, KeyCode::Char('/'))
+                    if matches!(app_state.mode, Mode::Normal) =>
+                {
+                    app_state.mode = Mode::Search;
+                    app_state.query.clear();
+                }
+                (KeyEventKind::Press, KeyCode::Char(c))
+                    if matches!(app_state.mode, Mode::Search) =>
+                {
+                    app_state.query.push(c);
+                    refilter(app_state)?;
+                }
+                (KeyEventKind::Press, KeyCode::Backspace)
+                    if matches!(app_state.mode, Mode::Search) =>
+                {
+                    app_state.query.pop();
+                    refilter(app_state)?;
+                }
                 (KeyEventKind::Press, KeyCode::Down) => {
                     let max_idx = app_state.db_rows.len().saturating_sub(1);
                     let selected = app_state.state.selected().unwrap_or(0);
@@ -134,21 +258,43 @@ fn handle_events(app_state: &mut AppState) -> Result<bool, Error> {
                 )),
                 (KeyEventKind::Press, KeyCode::Enter) => {
                     if !app_state.db_rows.is_empty() {
-                        Clipboard::new()
-                            .unwrap()
-                            .set_text(&app_state.db_rows[app_state.state.selected().unwrap()][0])
-                            .unwrap();
+                        let row = &app_state.db_rows[app_state.state.selected().unwrap()];
+                        restore_clip(row).unwrap();
                         return Ok(true);
                     }
                 }
                 (KeyEventKind::Press, KeyCode::Delete) => {
                     let selected = app_state.state.selected().unwrap_or(0);
                     if !app_state.db_rows.is_empty() {
-                        let clip = app_state.db_rows.remove(selected);
-                        let query = format!("DELETE FROM clips WHERE clip = '{}';", clip[0]);
-                        app_state
-                            .db
-                            .execute(query)
+                        let row = app_state.db_rows.remove(selected);
+                        let mut statement = match &row {
+                            ClipRow::Text { clip, .. } => {
+                                let mut statement = app_state
+                                    .db
+                                    .prepare("DELETE FROM clips WHERE clip = ?1;")
+                                    .change_context(Error)
+                                    .attach_printable("unable to prepare delete statement")?;
+                                statement
+                                    .bind((1, clip.as_str()))
+                                    .change_context(Error)
+                                    .attach_printable("unable to bind clip to delete statement")?;
+                                statement
+                            }
+                            ClipRow::Image { id, .. } => {
+                                let mut statement = app_state
+                                    .db
+                                    .prepare("DELETE FROM images WHERE id = ?1;")
+                                    .change_context(Error)
+                                    .attach_printable("unable to prepare delete statement")?;
+                                statement
+                                    .bind((1, *id))
+                                    .change_context(Error)
+                                    .attach_printable("unable to bind id to delete statement")?;
+                                statement
+                            }
+                        };
+                        statement
+                            .next()
                             .change_context(Error)
                             .attach_printable("delete from database failed")?;
                     }
@@ -163,58 +309,497 @@ fn handle_events(app_state: &mut AppState) -> Result<bool, Error> {
 fn ui(frame: &mut Frame, app_state: &mut AppState) {
     let main_layout =
         Layout::new(Direction::Vertical, [Constraint::Percentage(100)]).split(frame.size());
+    let title = match app_state.mode {
+        Mode::Search => format!("/{}", app_state.query),
+        Mode::Normal => String::new(),
+    };
     frame.render_stateful_widget(
         Table::new(
             app_state
                 .db_rows
-                .clone()
-                .into_iter()
-                .map(Row::new)
+                .iter()
+                .map(|row| Row::new([row_line(row, &app_state.query)]))
                 .collect::<Vec<Row<'_>>>(),
             [Constraint::Percentage(100)],
         )
         .highlight_style(Style::new().red().italic())
-        .block(Block::bordered()),
+        .block(Block::bordered().title(title)),
         main_layout[0],
         &mut app_state.state,
     );
 }
 
+/// Render a row's display text, highlighting the span that matched the
+/// current search query (if any), the way helix surfaces matches.
+fn row_line(row: &ClipRow, query: &str) -> Line<'static> {
+    let text = row.display();
+    if query.is_empty() {
+        return Line::from(text);
+    }
+
+    match find_case_insensitive(&text, query) {
+        Some((start, end)) => Line::from(vec![
+            Span::raw(text[..start].to_string()),
+            Span::styled(text[start..end].to_string(), Style::new().yellow().bold()),
+            Span::raw(text[end..].to_string()),
+        ]),
+        None => Line::from(text),
+    }
+}
+
+/// Find the byte range of the first case-insensitive match of `query` in
+/// `text`, expressed in `text`'s own byte offsets.
+///
+/// This compares `text` and `query` character-by-character (via
+/// `char::to_lowercase`) rather than lowercasing the whole of `text` and
+/// reusing the resulting offsets: some characters change byte length (and
+/// even character count, e.g. `İ` -> `i̇`) when lowercased, so a match
+/// found in a lowercased copy doesn't necessarily land on a char boundary
+/// back in the original `text` and slicing it can panic.
+fn find_case_insensitive(text: &str, query: &str) -> Option<(usize, usize)> {
+    let haystack: Vec<(usize, char)> = text.char_indices().collect();
+    let needle: Vec<char> = query.chars().collect();
+    if needle.is_empty() || needle.len() > haystack.len() {
+        return None;
+    }
+
+    for start in 0..=haystack.len() - needle.len() {
+        let is_match = haystack[start..start + needle.len()]
+            .iter()
+            .zip(&needle)
+            .all(|((_, h), n)| h.to_lowercase().eq(n.to_lowercase()));
+
+        if is_match {
+            let start_byte = haystack[start].0;
+            let end_byte = haystack
+                .get(start + needle.len())
+                .map(|(offset, _)| *offset)
+                .unwrap_or(text.len());
+            return Some((start_byte, end_byte));
+        }
+    }
+    None
+}
+
+/// A single row of clip history, along with the X11 selection it was
+/// captured from (`"clipboard"`, `"primary"`, or `"secondary"`).
+enum ClipRow {
+    Text {
+        clip: String,
+        selection: String,
+    },
+    Image {
+        id: i64,
+        width: i64,
+        height: i64,
+        data: Vec<u8>,
+        selection: String,
+    },
+}
+
+impl ClipRow {
+    /// What to show for this row in the TUI table.
+    fn display(&self) -> String {
+        match self {
+            ClipRow::Text { clip, .. } => clip.clone(),
+            ClipRow::Image { width, height, .. } => format!("[image {width}x{height}]"),
+        }
+    }
+}
+
+/// Whether the TUI is navigating history or typing into the `/` search
+/// prompt.
+enum Mode {
+    Normal,
+    Search,
+}
+
 struct AppState {
-    db_rows: Vec<[String; 1]>,
+    db_rows: Vec<ClipRow>,
     db: Connection,
     state: TableState,
+    mode: Mode,
+    query: String,
 }
 
 impl AppState {
-    fn new(db_rows: Vec<[String; 1]>, db: Connection) -> Self {
+    fn new(db_rows: Vec<ClipRow>, db: Connection) -> Self {
         Self {
             db_rows,
             db,
             state: TableState::default().with_selected(0),
+            mode: Mode::Normal,
+            query: String::new(),
+        }
+    }
+}
+
+/// Re-run `app_state.query` against the clip history: the full, unfiltered
+/// history when the query has no search terms (empty or all whitespace),
+/// or the FTS5 index otherwise.
+fn refilter(app_state: &mut AppState) -> Result<(), Error> {
+    let match_expr = fts_match_expr(&app_state.query);
+    app_state.db_rows = if match_expr.is_empty() {
+        read_rows(&app_state.db)
+    } else {
+        search_rows(&app_state.db, &match_expr)
+    }
+    .change_context(Error)
+    .attach_printable("unable to search clip history")?;
+    app_state.state.select(Some(0));
+    Ok(())
+}
+
+/// Find clips matching a prefix-query `match_expr` (see [`fts_match_expr`])
+/// via the `clips_fts` FTS5 index, ranked by relevance.
+fn search_rows(
+    db: &Connection,
+    match_expr: &str,
+) -> std::result::Result<Vec<ClipRow>, sqlite::Error> {
+    let mut statement = db.prepare(
+        "SELECT c.clip, c.selection
+         FROM clips_fts
+         JOIN clips c ON c.rowid = clips_fts.rowid
+         WHERE clips_fts MATCH ?1
+         ORDER BY rank;",
+    )?;
+    statement.bind((1, match_expr))?;
+
+    let mut rows = Vec::new();
+    while let State::Row = statement.next()? {
+        rows.push(ClipRow::Text {
+            clip: statement.read::<String, _>("clip")?,
+            selection: statement.read::<String, _>("selection")?,
+        });
+    }
+    Ok(rows)
+}
+
+/// Turn free-typed search text into an FTS5 prefix query, quoting each term
+/// so that punctuation in the query can't be read as FTS5 syntax.
+fn fts_match_expr(query: &str) -> String {
+    query
+        .split_whitespace()
+        .map(|term| format!("\"{}\"*", term.replace('"', "\"\"")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Read every clip and image row out of the database, ordered by insertion.
+///
+/// `clips` and `images` each have their own independent `rowid` sequence, so
+/// sorting by `rowid` would interleave the two non-chronologically (e.g. an
+/// image captured today could sort before text captured last week just
+/// because fewer images have been stored overall). Sort by `created_at`
+/// instead, which is shared across both tables.
+fn read_rows(db: &Connection) -> std::result::Result<Vec<ClipRow>, sqlite::Error> {
+    let mut rows = Vec::new();
+
+    let mut clips = db.prepare("SELECT clip, selection, created_at FROM clips;")?;
+    while let State::Row = clips.next()? {
+        rows.push((
+            clips.read::<i64, _>("created_at")?,
+            ClipRow::Text {
+                clip: clips.read::<String, _>("clip")?,
+                selection: clips.read::<String, _>("selection")?,
+            },
+        ));
+    }
+
+    let mut images =
+        db.prepare("SELECT id, width, height, data, selection, created_at FROM images;")?;
+    while let State::Row = images.next()? {
+        rows.push((
+            images.read::<i64, _>("created_at")?,
+            ClipRow::Image {
+                id: images.read::<i64, _>("id")?,
+                width: images.read::<i64, _>("width")?,
+                height: images.read::<i64, _>("height")?,
+                data: images.read::<Vec<u8>, _>("data")?,
+                selection: images.read::<String, _>("selection")?,
+            },
+        ));
+    }
+
+    rows.sort_by_key(|(created_at, _)| *created_at);
+    Ok(rows.into_iter().map(|(_, row)| row).collect())
+}
+
+/// Milliseconds since the unix epoch, used as a shared ordering key across
+/// the `clips` and `images` tables.
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock should be after the unix epoch")
+        .as_millis() as i64
+}
+
+/// Snapshot the live database to `path` using `VACUUM INTO`, rather than the
+/// `sqlite3_backup` API: both give a consistent, point-in-time copy safe to
+/// take while the daemon keeps writing, but `VACUUM INTO` does it in a
+/// single statement instead of the backup API's page-by-page stepping loop,
+/// at the cost of holding one read transaction open for the duration of the
+/// copy (fine for a clipboard-history database, which is small and not
+/// written to often enough for that to matter in practice). Unlike the C
+/// API's `sqlite3_backup`, `VACUUM INTO` refuses to write over a file that
+/// already exists, so a pre-existing `path` (e.g. re-running the same
+/// export) is removed first.
+fn export_db(db: &Connection, path: &Path) -> Result<(), Error> {
+    match fs::remove_file(path) {
+        Ok(()) => {}
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => {
+            return Err(err)
+                .change_context(Error)
+                .attach_printable("unable to remove the existing export file")
+        }
+    }
+
+    let mut statement = db
+        .prepare("VACUUM INTO ?1;")
+        .change_context(Error)
+        .attach_printable("unable to prepare export statement")?;
+    statement
+        .bind((1, path.to_string_lossy().as_ref()))
+        .change_context(Error)
+        .attach_printable("unable to bind export path")?;
+    statement
+        .next()
+        .change_context(Error)
+        .attach_printable("export to backup file failed")?;
+    Ok(())
+}
+
+/// Merge the clips and images from a previously exported database at `path`
+/// into the live database, attaching it rather than reading the file
+/// directly so concurrent writers are still respected. Clips dedup on their
+/// primary key; images have no natural key, so they're deduped by comparing
+/// `width`/`height`/`data` against what's already here, the same bytes an
+/// identical re-import would otherwise duplicate every time it ran.
+fn import_db(db: &Connection, path: &Path) -> Result<(), Error> {
+    let mut attach = db
+        .prepare("ATTACH DATABASE ?1 AS imported;")
+        .change_context(Error)
+        .attach_printable("unable to prepare attach statement")?;
+    attach
+        .bind((1, path.to_string_lossy().as_ref()))
+        .change_context(Error)
+        .attach_printable("unable to bind import path")?;
+    attach
+        .next()
+        .change_context(Error)
+        .attach_printable("unable to attach the import database")?;
+
+    let result = copy_imported_rows(db)
+        .change_context(Error)
+        .attach_printable("unable to copy rows from the import database");
+
+    db.execute("DETACH DATABASE imported;")
+        .change_context(Error)
+        .attach_printable("unable to detach the import database")?;
+
+    result
+}
+
+fn copy_imported_rows(db: &Connection) -> std::result::Result<(), sqlite::Error> {
+    let imported_at = now_millis();
+
+    let mut clips = db.prepare(
+        "INSERT OR IGNORE INTO clips (clip, selection, created_at)
+             SELECT clip, selection, ?1 FROM imported.clips;",
+    )?;
+    clips.bind((1, imported_at))?;
+    clips.next()?;
+
+    let mut images = db.prepare(
+        "INSERT INTO images (width, height, data, selection, created_at)
+             SELECT i.width, i.height, i.data, i.selection, ?1
+             FROM imported.images i
+             WHERE NOT EXISTS (
+                 SELECT 1 FROM images existing
+                 WHERE existing.width = i.width
+                   AND existing.height = i.height
+                   AND existing.data = i.data
+             );",
+    )?;
+    images.bind((1, imported_at))?;
+    images.next()?;
+
+    Ok(())
+}
+
+/// Persist a captured image clip as a BLOB alongside its dimensions.
+fn insert_image(
+    db: &Connection,
+    image: &arboard::ImageData<'_>,
+    selection: &str,
+) -> Result<(), Error> {
+    let mut statement = db
+        .prepare(
+            "INSERT INTO images (width, height, data, selection, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5);",
+        )
+        .change_context(Error)
+        .attach_printable("unable to prepare image insert statement")?;
+    statement
+        .bind((1, image.width as i64))
+        .change_context(Error)
+        .attach_printable("unable to bind width to image insert statement")?;
+    statement
+        .bind((2, image.height as i64))
+        .change_context(Error)
+        .attach_printable("unable to bind height to image insert statement")?;
+    statement
+        .bind((3, image.bytes.as_ref()))
+        .change_context(Error)
+        .attach_printable("unable to bind data to image insert statement")?;
+    statement
+        .bind((4, selection))
+        .change_context(Error)
+        .attach_printable("unable to bind selection to image insert statement")?;
+    statement
+        .bind((5, now_millis()))
+        .change_context(Error)
+        .attach_printable("unable to bind created_at to image insert statement")?;
+    statement
+        .next()
+        .change_context(Error)
+        .attach_printable("insertion into database failed")?;
+    Ok(())
+}
+
+/// Write a clip back to the clipboard, preferring the same X11 selection it
+/// was captured from on Linux. Other platforms only ever have one clipboard.
+fn restore_clip(row: &ClipRow) -> std::result::Result<(), arboard::Error> {
+    match row {
+        ClipRow::Text { clip, selection } => {
+            #[cfg(target_os = "linux")]
+            {
+                use arboard::SetExtLinux;
+                Clipboard::new()?
+                    .set()
+                    .clipboard(parse_selection(selection))
+                    .text(clip.as_str())
+            }
+            #[cfg(not(target_os = "linux"))]
+            {
+                let _ = selection;
+                Clipboard::new()?.set_text(clip.as_str())
+            }
+        }
+        ClipRow::Image {
+            width,
+            height,
+            data,
+            selection,
+            ..
+        } => {
+            let image = arboard::ImageData {
+                width: *width as usize,
+                height: *height as usize,
+                bytes: data.as_slice().into(),
+            };
+            #[cfg(target_os = "linux")]
+            {
+                use arboard::SetExtLinux;
+                Clipboard::new()?
+                    .set()
+                    .clipboard(parse_selection(selection))
+                    .image(image)
+            }
+            #[cfg(not(target_os = "linux"))]
+            {
+                let _ = selection;
+                Clipboard::new()?.set_image(image)
+            }
         }
     }
 }
 
+#[cfg(target_os = "linux")]
+fn selection_name(kind: arboard::LinuxClipboardKind) -> &'static str {
+    use arboard::LinuxClipboardKind;
+    match kind {
+        LinuxClipboardKind::Clipboard => "clipboard",
+        LinuxClipboardKind::Primary => "primary",
+        LinuxClipboardKind::Secondary => "secondary",
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn parse_selection(name: &str) -> arboard::LinuxClipboardKind {
+    use arboard::LinuxClipboardKind;
+    match name {
+        "primary" => LinuxClipboardKind::Primary,
+        "secondary" => LinuxClipboardKind::Secondary,
+        _ => LinuxClipboardKind::Clipboard,
+    }
+}
+
 #[cfg(target_os = "linux")]
 fn daemon(db: Connection) -> Result<(), Error> {
+    use arboard::GetExtLinux;
+    use arboard::LinuxClipboardKind;
     use arboard::SetExtLinux;
     let one_sec = time::Duration::from_secs(1);
     let mut clipboard = Clipboard::new().unwrap();
 
+    let selections = [
+        LinuxClipboardKind::Clipboard,
+        LinuxClipboardKind::Primary,
+        LinuxClipboardKind::Secondary,
+    ];
+    let mut previous_image: [Option<Vec<u8>>; 3] = [None, None, None];
+
     loop {
-        let text = clipboard.get_text().unwrap_or("pare_daemonized".into());
-
-        if text != "pare_daemonized" {
-            let query = format!(
-                "CREATE TABLE IF NOT EXISTS clips (clip TEXT PRIMARY KEY);
-             INSERT OR IGNORE INTO clips (clip) VALUES ('{text}');"
-            );
-            db.execute(query)
-                .change_context(Error)
-                .attach_printable("insertion into database failed")?;
-            clipboard.set().wait().text(text).unwrap();
-        } else {
+        let mut saw_change = false;
+
+        for (idx, kind) in selections.into_iter().enumerate() {
+            let text = clipboard
+                .get()
+                .clipboard(kind)
+                .text()
+                .unwrap_or("pare_daemonized".into());
+
+            if text != "pare_daemonized" {
+                saw_change = true;
+                let mut statement = db
+                    .prepare(
+                        "INSERT OR IGNORE INTO clips (clip, selection, created_at)
+                             VALUES (?1, ?2, ?3);",
+                    )
+                    .change_context(Error)
+                    .attach_printable("unable to prepare insert statement")?;
+                statement
+                    .bind((1, text.as_str()))
+                    .change_context(Error)
+                    .attach_printable("unable to bind clip to insert statement")?;
+                statement
+                    .bind((2, selection_name(kind)))
+                    .change_context(Error)
+                    .attach_printable("unable to bind selection to insert statement")?;
+                statement
+                    .bind((3, now_millis()))
+                    .change_context(Error)
+                    .attach_printable("unable to bind created_at to insert statement")?;
+                statement
+                    .next()
+                    .change_context(Error)
+                    .attach_printable("insertion into database failed")?;
+                clipboard.set().clipboard(kind).text(text).unwrap();
+                continue;
+            }
+
+            if let Ok(image) = clipboard.get().clipboard(kind).image() {
+                if previous_image[idx].as_deref() != Some(image.bytes.as_ref()) {
+                    saw_change = true;
+                    insert_image(&db, &image, selection_name(kind))?;
+                    previous_image[idx] = Some(image.bytes.clone().into_owned());
+                }
+            }
+        }
+
+        if !saw_change {
             // The clipboard might not be intialized with anything so we
             // need to wait until something is on the clipboard
             thread::sleep(one_sec);
@@ -222,36 +807,61 @@ fn daemon(db: Connection) -> Result<(), Error> {
     }
 }
 
+/// On platforms with a native change-notification API, the daemon loop is
+/// just: ask the monitor for the next change, write it down. No timers, no
+/// re-reading content that hasn't moved.
 #[cfg(not(target_os = "linux"))]
-// TODO: Setup clipboard event monitoring for OSX
-// TODO: Setup clipboard event monitoring for Windows
-// NOTE: Doing a polling busy loop isn't ideal, but it is what it is for now
 fn daemon(db: Connection) -> Result<(), Error> {
-    let one_sec = time::Duration::from_secs(1);
-
-    let mut clipboard = Clipboard::new().unwrap();
+    let mut monitor = monitor::platform_monitor()?;
 
-    let mut previous = String::new();
     loop {
-        let current = clipboard.get_text().unwrap_or("pared_daemonized".into());
-        if current != previous {
-            let query = format!(
-                "CREATE TABLE IF NOT EXISTS clips (clip STRING PRIMARY KEY);
-                 INSERT OR IGNORE INTO clips (clip) VALUES ('{current}');"
-            );
-
-            db.execute(query)
-                .change_context(Error)
-                .attach_printable("insertion into database failed")?;
-            previous = current;
-        } else {
-            thread::sleep(one_sec);
+        match monitor.next_change()? {
+            Clip::Text(text) => {
+                let mut statement = db
+                    .prepare(
+                        "INSERT OR IGNORE INTO clips (clip, selection, created_at)
+                             VALUES (?1, ?2, ?3);",
+                    )
+                    .change_context(Error)
+                    .attach_printable("unable to prepare insert statement")?;
+                statement
+                    .bind((1, text.as_str()))
+                    .change_context(Error)
+                    .attach_printable("unable to bind clip to insert statement")?;
+                statement
+                    .bind((2, "clipboard"))
+                    .change_context(Error)
+                    .attach_printable("unable to bind selection to insert statement")?;
+                statement
+                    .bind((3, now_millis()))
+                    .change_context(Error)
+                    .attach_printable("unable to bind created_at to insert statement")?;
+                statement
+                    .next()
+                    .change_context(Error)
+                    .attach_printable("insertion into database failed")?;
+            }
+            Clip::Image {
+                width,
+                height,
+                data,
+            } => {
+                insert_image(
+                    &db,
+                    &arboard::ImageData {
+                        width,
+                        height,
+                        bytes: data.into(),
+                    },
+                    "clipboard",
+                )?;
+            }
         }
     }
 }
 
 #[derive(Debug)]
-struct Error;
+pub(crate) struct Error;
 
 impl fmt::Display for Error {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -0,0 +1,72 @@
+//! macOS backend for [`super::ClipboardMonitor`], built on `NSPasteboard`'s
+//! `changeCount` rather than hashing the pasteboard contents every tick.
+//!
+//! `changeCount` increments every time anything writes to the general
+//! pasteboard, so sleeping briefly and only reading `arboard` when the count
+//! moves avoids both the redundant reads and the duplicate DB writes the
+//! plain polling loop used to produce.
+
+use super::Clip;
+use super::ClipboardMonitor;
+use crate::Error;
+use arboard::Clipboard;
+use error_stack::Result;
+use error_stack::ResultExt;
+use objc::class;
+use objc::msg_send;
+use objc::runtime::Object;
+use objc::sel;
+use objc::sel_impl;
+use std::thread;
+use std::time::Duration;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+pub(crate) struct ChangeCountMonitor {
+    clipboard: Clipboard,
+    last_change_count: i64,
+}
+
+impl ChangeCountMonitor {
+    pub(crate) fn new() -> Result<Self, Error> {
+        let clipboard = Clipboard::new()
+            .change_context(Error)
+            .attach_printable("unable to get access to the clipboard")?;
+        Ok(Self {
+            clipboard,
+            last_change_count: general_pasteboard_change_count(),
+        })
+    }
+}
+
+impl ClipboardMonitor for ChangeCountMonitor {
+    fn next_change(&mut self) -> Result<Clip, Error> {
+        loop {
+            let change_count = general_pasteboard_change_count();
+            if change_count != self.last_change_count {
+                self.last_change_count = change_count;
+
+                if let Ok(text) = self.clipboard.get_text() {
+                    return Ok(Clip::Text(text));
+                }
+                if let Ok(image) = self.clipboard.get_image() {
+                    return Ok(Clip::Image {
+                        width: image.width,
+                        height: image.height,
+                        data: image.bytes.into_owned(),
+                    });
+                }
+            }
+
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+/// `[[NSPasteboard generalPasteboard] changeCount]`
+fn general_pasteboard_change_count() -> i64 {
+    unsafe {
+        let pasteboard: *mut Object = msg_send![class!(NSPasteboard), generalPasteboard];
+        msg_send![pasteboard, changeCount]
+    }
+}
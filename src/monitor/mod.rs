@@ -0,0 +1,42 @@
+//! Event-driven clipboard watching for platforms that expose a change
+//! notification instead of forcing a busy poll loop.
+//!
+//! The Linux daemon watches X11 selections directly in `main.rs` today, but
+//! the D-Bus portal backend could implement [`ClipboardMonitor`] too, so the
+//! trait lives here rather than under a `cfg(not(target_os = "linux"))` gate.
+
+#[cfg(target_os = "macos")]
+mod macos;
+#[cfg(target_os = "windows")]
+mod windows;
+
+use crate::Error;
+use error_stack::Result;
+
+/// A clip captured off the system clipboard by a [`ClipboardMonitor`].
+pub(crate) enum Clip {
+    Text(String),
+    Image {
+        width: usize,
+        height: usize,
+        data: Vec<u8>,
+    },
+}
+
+/// A backend that blocks until the clipboard changes and reports what it now
+/// holds. Implementations should avoid re-reading or re-reporting content
+/// that hasn't actually changed.
+pub(crate) trait ClipboardMonitor {
+    fn next_change(&mut self) -> Result<Clip, Error>;
+}
+
+/// Build the change-notification backed monitor for the current platform.
+#[cfg(target_os = "macos")]
+pub(crate) fn platform_monitor() -> Result<Box<dyn ClipboardMonitor>, Error> {
+    Ok(Box::new(macos::ChangeCountMonitor::new()?))
+}
+
+#[cfg(target_os = "windows")]
+pub(crate) fn platform_monitor() -> Result<Box<dyn ClipboardMonitor>, Error> {
+    Ok(Box::new(windows::FormatListenerMonitor::new()?))
+}
@@ -0,0 +1,134 @@
+//! Windows backend for [`super::ClipboardMonitor`], driven by
+//! `WM_CLIPBOARDUPDATE` instead of a timed poll.
+//!
+//! A hidden message-only window registers for clipboard update
+//! notifications via `AddClipboardFormatListener`, then blocks in an
+//! ordinary Win32 message loop until one arrives.
+
+use super::Clip;
+use super::ClipboardMonitor;
+use crate::Error;
+use arboard::Clipboard;
+use error_stack::Result;
+use error_stack::ResultExt;
+use windows::core::w;
+use windows::Win32::Foundation::HWND;
+use windows::Win32::Foundation::LPARAM;
+use windows::Win32::Foundation::LRESULT;
+use windows::Win32::Foundation::WPARAM;
+use windows::Win32::System::DataExchange::AddClipboardFormatListener;
+use windows::Win32::System::DataExchange::RemoveClipboardFormatListener;
+use windows::Win32::UI::WindowsAndMessaging::CreateWindowExW;
+use windows::Win32::UI::WindowsAndMessaging::DefWindowProcW;
+use windows::Win32::UI::WindowsAndMessaging::DestroyWindow;
+use windows::Win32::UI::WindowsAndMessaging::DispatchMessageW;
+use windows::Win32::UI::WindowsAndMessaging::GetMessageW;
+use windows::Win32::UI::WindowsAndMessaging::RegisterClassExW;
+use windows::Win32::UI::WindowsAndMessaging::TranslateMessage;
+use windows::Win32::UI::WindowsAndMessaging::HWND_MESSAGE;
+use windows::Win32::UI::WindowsAndMessaging::MSG;
+use windows::Win32::UI::WindowsAndMessaging::WM_CLIPBOARDUPDATE;
+use windows::Win32::UI::WindowsAndMessaging::WNDCLASSEXW;
+
+pub(crate) struct FormatListenerMonitor {
+    clipboard: Clipboard,
+    hwnd: HWND,
+}
+
+impl Drop for FormatListenerMonitor {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = RemoveClipboardFormatListener(self.hwnd);
+            let _ = DestroyWindow(self.hwnd);
+        }
+    }
+}
+
+impl FormatListenerMonitor {
+    pub(crate) fn new() -> Result<Self, Error> {
+        let clipboard = Clipboard::new()
+            .change_context(Error)
+            .attach_printable("unable to get access to the clipboard")?;
+
+        let hwnd = create_message_window()
+            .change_context(Error)
+            .attach_printable("unable to create the clipboard listener window")?;
+
+        unsafe {
+            AddClipboardFormatListener(hwnd)
+                .ok()
+                .change_context(Error)
+                .attach_printable("unable to register for clipboard update notifications")?;
+        }
+
+        Ok(Self { clipboard, hwnd })
+    }
+}
+
+impl ClipboardMonitor for FormatListenerMonitor {
+    fn next_change(&mut self) -> Result<Clip, Error> {
+        loop {
+            wait_for_clipboard_update();
+
+            if let Ok(text) = self.clipboard.get_text() {
+                return Ok(Clip::Text(text));
+            }
+            if let Ok(image) = self.clipboard.get_image() {
+                return Ok(Clip::Image {
+                    width: image.width,
+                    height: image.height,
+                    data: image.bytes.into_owned(),
+                });
+            }
+        }
+    }
+}
+
+/// Pump the message queue until a `WM_CLIPBOARDUPDATE` arrives.
+fn wait_for_clipboard_update() {
+    let mut msg = MSG::default();
+    unsafe {
+        while GetMessageW(&mut msg, HWND(0), 0, 0).as_bool() {
+            let _ = TranslateMessage(&msg);
+            DispatchMessageW(&msg);
+            if msg.message == WM_CLIPBOARDUPDATE {
+                return;
+            }
+        }
+    }
+}
+
+unsafe extern "system" fn wndproc(hwnd: HWND, msg: u32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    DefWindowProcW(hwnd, msg, wparam, lparam)
+}
+
+/// A hidden, message-only window exists purely to receive
+/// `WM_CLIPBOARDUPDATE`; it's never shown and has no parent in the visible
+/// window hierarchy.
+fn create_message_window() -> windows::core::Result<HWND> {
+    unsafe {
+        let class_name = w!("pare_clipboard_listener");
+        let class = WNDCLASSEXW {
+            cbSize: std::mem::size_of::<WNDCLASSEXW>() as u32,
+            lpfnWndProc: Some(wndproc),
+            lpszClassName: class_name,
+            ..Default::default()
+        };
+        RegisterClassExW(&class);
+
+        CreateWindowExW(
+            Default::default(),
+            class_name,
+            w!("pare"),
+            Default::default(),
+            0,
+            0,
+            0,
+            0,
+            HWND_MESSAGE,
+            None,
+            None,
+            None,
+        )
+    }
+}